@@ -14,7 +14,7 @@ static CONN_STR: Lazy<String> = Lazy::new(|| {
 async fn main() -> anyhow::Result<()> {
     let config = Config::from_ado_string(&CONN_STR)?;
 
-        let tcp = TcpStream::connect(config.get_addr()).await.unwrap();
+        let tcp = TcpStream::connect(config.get_addr().unwrap()).await.unwrap();
         tcp.set_nodelay(true);
 
         let mut client = Client::connect(config, tcp.compat_write()).await.unwrap();
@@ -2,6 +2,12 @@ mod auth;
 mod config;
 mod connection;
 
+#[cfg(all(feature = "pool", feature = "tokio", not(target_arch = "wasm32")))]
+mod pool;
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+mod transport;
+
 mod tls;
 #[cfg(any(
     feature = "rustls",
@@ -13,11 +19,13 @@ mod tls_stream;
 pub use auth::*;
 pub use config::*;
 pub(crate) use connection::*;
+#[cfg(all(feature = "pool", feature = "tokio", not(target_arch = "wasm32")))]
+pub use pool::{ConnectionManager, PooledClient};
 
 use crate::{
     tds::{
         codec::{self},
-        stream::{QueryStream, TokenStream},
+        stream::{ExecuteResult, QueryStream, TokenStream},
     },
     SqlReadBytes, ToSql,
 };
@@ -46,7 +54,7 @@ use std::{borrow::Cow, fmt::Debug};
 /// config.port(1433);
 /// config.authentication(AuthMethod::sql_server("SA", "<Mys3cureP4ssW0rD>"));
 ///
-/// let tcp = tokio::net::TcpStream::connect(config.get_addr()).await?;
+/// let tcp = tokio::net::TcpStream::connect(config.get_addr().unwrap()).await?;
 /// tcp.set_nodelay(true)?;
 /// // Client is ready to use.
 /// let client = tiberius::Client::connect(config, tcp.compat_write()).await?;
@@ -99,7 +107,7 @@ impl<S: AsyncRead + AsyncWrite + Unpin + Send> Client<S> {
     /// #     "server=tcp:localhost,1433;integratedSecurity=true;TrustServerCertificate=true".to_owned(),
     /// # );
     /// # let config = Config::from_ado_string(&c_str)?;
-    /// # let tcp = tokio::net::TcpStream::connect(config.get_addr()).await?;
+    /// # let tcp = tokio::net::TcpStream::connect(config.get_addr().unwrap()).await?;
     /// # tcp.set_nodelay(true)?;
     /// # let mut client = tiberius::Client::connect(config, tcp.compat_write()).await?;
     /// let stream = client
@@ -138,6 +146,31 @@ impl<S: AsyncRead + AsyncWrite + Unpin + Send> Client<S> {
         Ok(result)
     }
 
+    /// Executes SQL statements in the SQL Server, returning the number of
+    /// affected rows. Useful for `INSERT`, `UPDATE` and `DELETE` statements.
+    /// The `query` can define the parameter placement and batching the same
+    /// way as in [`query`].
+    ///
+    /// [`query`]: #method.query
+    pub async fn execute<'a, 'b>(
+        &'a mut self,
+        query: impl Into<Cow<'b, str>>,
+        params: &'b [&'b dyn ToSql],
+    ) -> crate::Result<ExecuteResult>
+    where
+        'a: 'b,
+    {
+        self.connection.flush_stream().await?;
+        let rpc_params = Self::rpc_params(query);
+
+        let params = params.iter().map(|p| p.to_sql());
+        self.rpc_perform_query(RpcProcId::ExecuteSQL, rpc_params, params)
+            .await?;
+
+        let ts = TokenStream::new(&mut self.connection);
+        ExecuteResult::new(ts.try_unfold()).await
+    }
+
     /// Closes this database connection explicitly.
     pub async fn close(self) -> crate::Result<()> {
         self.connection.close().await
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct SqlServerAuth {
@@ -25,18 +25,54 @@ impl Debug for SqlServerAuth {
     }
 }
 
+/// Fetches an AAD token on demand, letting callers plug in their own
+/// refresh logic (e.g. the Azure Identity SDK) instead of handing
+/// `tiberius` a token that will eventually expire.
+///
+/// [`Config::connect`] calls [`fetch_token`](TokenProvider::fetch_token)
+/// once per connection attempt and authenticates with whatever token comes
+/// back, so a provider backed by a cache only needs to refresh the token
+/// when it's actually stale.
+///
+/// [`Config::connect`]: struct.Config.html#method.connect
+#[async_trait::async_trait]
+pub trait TokenProvider: Debug + Send + Sync {
+    /// Returns a token to authenticate with. Called fresh for every
+    /// connection attempt, so implementations that cache a token should
+    /// check its expiry here rather than in a background task.
+    async fn fetch_token(&self) -> crate::Result<String>;
+}
+
 /// Defines the method of authentication to the server.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub enum AuthMethod {
     /// Authenticate directly with SQL Server.
     SqlServer(SqlServerAuth),
     /// Authenticate with an AAD token. The token should encode an AAD user/service principal
     /// which has access to SQL Server.
     AADToken(String),
+    /// Authenticate with an AAD token fetched from a [`TokenProvider`] right
+    /// before connecting, so the token handed to the server is never more
+    /// stale than a single connection attempt.
+    AADTokenProvider(Arc<dyn TokenProvider>),
     #[doc(hidden)]
     None,
 }
 
+impl PartialEq for AuthMethod {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::SqlServer(a), Self::SqlServer(b)) => a == b,
+            (Self::AADToken(a), Self::AADToken(b)) => a == b,
+            (Self::AADTokenProvider(a), Self::AADTokenProvider(b)) => Arc::ptr_eq(a, b),
+            (Self::None, Self::None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for AuthMethod {}
+
 impl AuthMethod {
     /// Construct a new SQL Server authentication configuration.
     pub fn sql_server(user: impl ToString, password: impl ToString) -> Self {
@@ -50,4 +86,10 @@ impl AuthMethod {
     pub fn aad_token(token: impl ToString) -> Self {
         Self::AADToken(token.to_string())
     }
+
+    /// Construct a new configuration that fetches its AAD token from
+    /// `provider` right before each connection attempt.
+    pub fn aad_token_provider(provider: impl TokenProvider + 'static) -> Self {
+        Self::AADTokenProvider(Arc::new(provider))
+    }
 }
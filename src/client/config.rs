@@ -0,0 +1,268 @@
+use crate::AuthMethod;
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use crate::Client;
+
+/// Defines how the traffic to the server is encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionLevel {
+    /// Only the login packet is encrypted, everything else is sent in
+    /// plaintext.
+    Off,
+    /// The whole connection is encrypted, given the server supports it.
+    On,
+    /// The connection is always encrypted; connecting fails if the server
+    /// cannot negotiate TLS.
+    Required,
+}
+
+/// Describes how to connect to a SQL Server instance: the address, the
+/// authentication method and the encryption settings.
+///
+/// A `Config` by itself does not open a connection. Either hand an already
+/// established stream to [`Client::connect`], or, when the `tokio` or
+/// `async-std` feature is enabled, call [`Config::connect`] to have the
+/// socket and TLS handshake handled for you.
+///
+/// [`Client::connect`]: struct.Client.html#method.connect
+/// [`Config::connect`]: struct.Config.html#method.connect
+#[derive(Debug, Clone)]
+pub struct Config {
+    host: String,
+    port: u16,
+    database: Option<String>,
+    encryption: EncryptionLevel,
+    trust_cert: bool,
+    authentication: AuthMethod,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Config {
+    /// Creates a new `Config` with sane defaults: `localhost:1433`, no
+    /// database selected, encryption off and no authentication configured.
+    pub fn new() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 1433,
+            database: None,
+            encryption: EncryptionLevel::Off,
+            trust_cert: false,
+            authentication: AuthMethod::None,
+        }
+    }
+
+    /// Sets the server hostname or IP address. On the `tokio` runtime,
+    /// [`Config::connect`] also accepts a `np:\\.\pipe\...` named pipe path
+    /// or a Unix socket path (starting with `/` or `unix:`) here; for those
+    /// forms [`get_addr`] returns `None` rather than a bogus `host:port`
+    /// string, since only `Config::connect` on `tokio` knows how to open
+    /// them. On `async-std`, `Config::connect` returns an error for them.
+    ///
+    /// [`Config::connect`]: struct.Config.html#method.connect
+    /// [`get_addr`]: struct.Config.html#method.get_addr
+    pub fn host(&mut self, host: impl ToString) {
+        self.host = host.to_string();
+    }
+
+    /// Sets the server port.
+    pub fn port(&mut self, port: u16) {
+        self.port = port;
+    }
+
+    /// Sets the database to use once connected.
+    pub fn database(&mut self, database: impl ToString) {
+        self.database = Some(database.to_string());
+    }
+
+    /// Sets the method of authenticating to the server.
+    pub fn authentication(&mut self, authentication: AuthMethod) {
+        self.authentication = authentication;
+    }
+
+    /// Sets the level of encryption to request from the server.
+    pub fn encryption(&mut self, encryption: EncryptionLevel) {
+        self.encryption = encryption;
+    }
+
+    /// Accepts the server's TLS certificate without validating it against a
+    /// trusted root. Useful for testing against servers with self-signed
+    /// certificates.
+    pub fn trust_cert(&mut self) {
+        self.trust_cert = true;
+    }
+
+    /// The address to open a `TcpStream` against, as `host:port`.
+    ///
+    /// Returns `None` when [`host`] was set to a `np:` named pipe path or a
+    /// Unix socket path (a leading `/`, or a `unix:` prefix), since those
+    /// forms have no `host:port` representation — only [`Config::connect`]
+    /// on the `tokio` runtime understands them, resolving them through the
+    /// transport layer
+    /// instead of opening a bare `TcpStream`.
+    ///
+    /// [`host`]: struct.Config.html#method.host
+    /// [`Config::connect`]: struct.Config.html#method.connect
+    pub fn get_addr(&self) -> Option<String> {
+        if is_non_tcp_host(&self.host) {
+            None
+        } else {
+            Some(format!("{}:{}", self.host, self.port))
+        }
+    }
+
+    pub(crate) fn database_name(&self) -> Option<&str> {
+        self.database.as_deref()
+    }
+
+    pub(crate) fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub(crate) fn auth_method(&self) -> &AuthMethod {
+        &self.authentication
+    }
+
+    pub(crate) fn encryption_level(&self) -> EncryptionLevel {
+        self.encryption
+    }
+
+    pub(crate) fn trust_server_certificate(&self) -> bool {
+        self.trust_cert
+    }
+
+    /// Parses a `Config` out of an ADO.NET style connection string, e.g.
+    /// `server=tcp:localhost,1433;database=tiberius;user=SA;password=<pw>`.
+    pub fn from_ado_string(s: &str) -> crate::Result<Self> {
+        let mut config = Self::new();
+        let mut user: Option<String> = None;
+        let mut password: Option<String> = None;
+
+        for part in s.split(';').filter(|p| !p.is_empty()) {
+            let mut kv = part.splitn(2, '=');
+
+            let key = kv.next().unwrap_or_default().trim().to_lowercase();
+            let value = kv.next().unwrap_or_default().trim();
+
+            match key.as_str() {
+                "server" => {
+                    let addr = value.trim_start_matches("tcp:");
+                    let mut parts = addr.splitn(2, ',');
+
+                    if let Some(host) = parts.next() {
+                        config.host(host);
+                    }
+
+                    if let Some(port) = parts.next() {
+                        config.port(port.parse().map_err(|_| {
+                            crate::error::Error::Conversion(
+                                format!("invalid port in connection string: {}", port).into(),
+                            )
+                        })?);
+                    }
+                }
+                "database" => config.database(value),
+                "user" | "user id" | "uid" => user = Some(value.to_string()),
+                "password" | "pwd" => password = Some(value.to_string()),
+                "trustservercertificate" => {
+                    if value.eq_ignore_ascii_case("true") {
+                        config.trust_cert();
+                    }
+                }
+                "encrypt" => {
+                    if value.eq_ignore_ascii_case("true") {
+                        config.encryption(EncryptionLevel::Required);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(user), Some(password)) = (user, password) {
+            config.authentication(AuthMethod::sql_server(user, password));
+        }
+
+        Ok(config)
+    }
+}
+
+/// Whether `host` is one of the non-TCP address forms (`np:` named pipe,
+/// `unix:` or a bare leading `/` for a Unix socket) that [`Config::get_addr`]
+/// cannot represent as `host:port`.
+fn is_non_tcp_host(host: &str) -> bool {
+    host.starts_with("np:") || host.starts_with("unix:") || host.starts_with('/')
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+type RuntimeStream = super::transport::Transport;
+
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+type RuntimeStream = async_std::net::TcpStream;
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+impl Config {
+    /// Opens a connection using this `Config`, handling the socket setup
+    /// that otherwise has to be repeated at every call site: resolves the
+    /// address (TCP, or on `tokio` a Unix socket or named pipe, see
+    /// [`get_addr`]), opens it on the active runtime and disables Nagle for
+    /// TCP. The raw stream is then handed to [`Client::connect`], which
+    /// negotiates TLS itself based on [`encryption_level`] — the same path
+    /// every other caller in this crate goes through, so there's only ever
+    /// one TLS handshake per connection.
+    ///
+    /// For custom transports (e.g. an already-open stream, or one coming
+    /// from outside tokio/async-std), use [`Client::connect`] directly.
+    ///
+    /// [`get_addr`]: struct.Config.html#method.get_addr
+    /// [`encryption_level`]: struct.Config.html#method.encryption_level
+    /// [`Client::connect`]: struct.Client.html#method.connect
+    pub async fn connect(&self) -> crate::Result<Client<RuntimeStream>> {
+        #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+        let raw = {
+            let address = super::transport::Address::parse(self.host(), self.port);
+            super::transport::connect(&address).await?
+        };
+
+        #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+        let raw = {
+            let addr = self.get_addr().ok_or_else(|| {
+                crate::error::Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "Unix sockets and named pipes are only supported on the tokio runtime",
+                ))
+            })?;
+
+            let tcp = async_std::net::TcpStream::connect(addr).await?;
+            tcp.set_nodelay(true)?;
+            tcp
+        };
+
+        let config = self.resolve_auth().await?;
+
+        Client::connect(config, raw).await
+    }
+
+    /// Resolves a [`AuthMethod::AADTokenProvider`] into a plain
+    /// [`AuthMethod::AADToken`] by calling its
+    /// [`fetch_token`](crate::TokenProvider::fetch_token) right before
+    /// connecting, so every connection attempt authenticates with a fresh
+    /// token instead of one that was resolved once and cached in the
+    /// `Config`. Every other authentication method is returned unchanged.
+    async fn resolve_auth(&self) -> crate::Result<Self> {
+        match &self.authentication {
+            AuthMethod::AADTokenProvider(provider) => {
+                let token = provider.fetch_token().await?;
+
+                let mut config = self.clone();
+                config.authentication = AuthMethod::AADToken(token);
+
+                Ok(config)
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+}
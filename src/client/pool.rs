@@ -0,0 +1,66 @@
+use crate::{client::transport::Transport, Client, Config};
+
+/// The connection type handed out by [`ConnectionManager`]. This is exactly
+/// what [`Config::connect`] produces, so TLS negotiated inside
+/// `Connection::connect` and non-TCP transports (Unix sockets, named
+/// pipes) are honored the same way they are everywhere else in the crate.
+///
+/// [`Config::connect`]: struct.Config.html#method.connect
+pub type PooledClient = Client<Transport>;
+
+/// A connection manager that opens and validates `tiberius` connections on
+/// demand, implementing [`bb8::ManageConnection`] so a [`ConnectionManager`]
+/// can be dropped straight into a [`bb8::Pool`].
+///
+/// This mirrors the manager pattern used by `r2d2`-style SQL pools: the
+/// manager itself is cheap to clone and holds only a [`Config`], connections
+/// are opened lazily by the pool, and liveness is checked by round-tripping
+/// a trivial query rather than inspecting socket state.
+///
+/// # Example
+///
+/// ```no_run
+/// # use tiberius::{Config, ConnectionManager};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Config::new();
+/// let manager = ConnectionManager::new(config);
+/// let pool = bb8::Pool::builder().build(manager).await?;
+///
+/// let mut conn = pool.get().await?;
+/// conn.query("SELECT 1", &[]).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Config`]: struct.Config.html
+#[derive(Clone, Debug)]
+pub struct ConnectionManager {
+    config: Config,
+}
+
+impl ConnectionManager {
+    /// Creates a new manager from a [`Config`]. No connection is opened
+    /// until the pool calls [`connect`](bb8::ManageConnection::connect).
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl bb8::ManageConnection for ConnectionManager {
+    type Connection = PooledClient;
+    type Error = crate::error::Error;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        self.config.connect().await
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        conn.query("SELECT 1", &[]).await?.into_row().await?;
+        Ok(())
+    }
+
+    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        false
+    }
+}
@@ -1,13 +1,75 @@
 use crate::Config;
 use futures_util::io::{AsyncRead, AsyncWrite};
 
-mod native_tls_stream;
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
 
-pub(crate) use native_tls_stream::TlsStream;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use native::TlsStream;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasm::TlsStream;
 
+/// Wraps `stream` in TLS. Off `wasm32`, this picks the backend implementation
+/// compiled in via the `native-tls`, `vendored-openssl` or `rustls` feature,
+/// the OpenSSL-based one taking priority when more than one is enabled. On
+/// `wasm32` there's no native TLS stack available; see [`wasm`] for what
+/// that means for callers.
 pub(crate) async fn create_tls_stream<S: AsyncRead + AsyncWrite + Unpin + Send>(
     config: &Config,
     stream: S,
 ) -> crate::Result<TlsStream<S>> {
-    native_tls_stream::create_tls_stream(config, stream).await
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        native::create_tls_stream(config, stream).await
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        wasm::create_tls_stream(config, stream).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AuthMethod, Config};
+    use tokio_util::compat::TokioAsyncWriteCompatExt;
+
+    fn tls_test_config() -> Config {
+        let mut config = Config::from_ado_string(
+            &std::env::var("TIBERIUS_TEST_CONNECTION_STRING").unwrap_or_else(|_| {
+                "server=tcp:localhost,1433;TrustServerCertificate=true;Encrypt=true".to_owned()
+            }),
+        )
+        .unwrap();
+
+        if let AuthMethod::None = config.auth_method() {
+            config.authentication(AuthMethod::sql_server("SA", "<Mys3cureP4ssW0rD>"));
+        }
+
+        config
+    }
+
+    #[cfg(feature = "native-tls")]
+    #[tokio::test]
+    #[ignore]
+    async fn connects_through_native_tls() {
+        let config = tls_test_config();
+        let tcp = tokio::net::TcpStream::connect(config.get_addr().unwrap()).await.unwrap();
+        tcp.set_nodelay(true).unwrap();
+
+        crate::Client::connect(config, tcp.compat_write()).await.unwrap();
+    }
+
+    #[cfg(feature = "rustls")]
+    #[tokio::test]
+    #[ignore]
+    async fn connects_through_rustls() {
+        let config = tls_test_config();
+        let tcp = tokio::net::TcpStream::connect(config.get_addr().unwrap()).await.unwrap();
+        tcp.set_nodelay(true).unwrap();
+
+        crate::Client::connect(config, tcp.compat_write()).await.unwrap();
+    }
 }
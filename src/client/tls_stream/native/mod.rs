@@ -0,0 +1,39 @@
+use crate::Config;
+use futures_util::io::{AsyncRead, AsyncWrite};
+
+#[cfg(any(feature = "native-tls", feature = "vendored-openssl"))]
+mod native_tls_stream;
+
+#[cfg(feature = "rustls")]
+mod rustls_tls_stream;
+
+#[cfg(any(feature = "native-tls", feature = "vendored-openssl"))]
+pub(crate) use native_tls_stream::TlsStream;
+
+#[cfg(all(
+    feature = "rustls",
+    not(any(feature = "native-tls", feature = "vendored-openssl"))
+))]
+pub(crate) use rustls_tls_stream::TlsStream;
+
+/// Wraps `stream` in TLS, picking the backend implementation compiled in
+/// via the `native-tls`, `vendored-openssl` or `rustls` feature. When more
+/// than one is enabled, the OpenSSL-based backend takes priority, matching
+/// the existing default before `rustls` support was added.
+pub(crate) async fn create_tls_stream<S: AsyncRead + AsyncWrite + Unpin + Send>(
+    config: &Config,
+    stream: S,
+) -> crate::Result<TlsStream<S>> {
+    #[cfg(any(feature = "native-tls", feature = "vendored-openssl"))]
+    {
+        native_tls_stream::create_tls_stream(config, stream).await
+    }
+
+    #[cfg(all(
+        feature = "rustls",
+        not(any(feature = "native-tls", feature = "vendored-openssl"))
+    ))]
+    {
+        rustls_tls_stream::create_tls_stream(config, stream).await
+    }
+}
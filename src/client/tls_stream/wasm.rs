@@ -0,0 +1,22 @@
+use crate::Config;
+use futures_util::io::{AsyncRead, AsyncWrite};
+
+/// On `wasm32` there's no native TLS stack to link against, so this type is
+/// the stream itself, and [`create_tls_stream`] never wraps a stream: it
+/// always returns an error. The stream handed to [`Client::connect`] is
+/// expected to already be secure, e.g. a `wss://` WebSocket supplied by the
+/// JS host.
+///
+/// [`Client::connect`]: ../../struct.Client.html#method.connect
+pub(crate) type TlsStream<S> = S;
+
+pub(crate) async fn create_tls_stream<S: AsyncRead + AsyncWrite + Unpin + Send>(
+    _config: &Config,
+    _stream: S,
+) -> crate::Result<TlsStream<S>> {
+    Err(crate::error::Error::Tls(
+        "TLS must be negotiated by the host environment on wasm32; pass an already-secure \
+         stream into Client::connect instead of requesting encryption from Config"
+            .into(),
+    ))
+}
@@ -1,8 +1,9 @@
+use futures_util::StreamExt;
 use tokio::{net::TcpStream, runtime::{Builder, Runtime}};
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
 use crate::{
-    tds::stream::QueryStream, Client, Config, ToSql
+    tds::stream::{ExecuteResult, QueryStream}, Client, Config, Row, ToSql
 };
 
 
@@ -20,14 +21,18 @@ impl TokioSyncClient {
             .unwrap();
 
         let client = runtime.block_on(async {
-            let tcp = TcpStream::connect(config.get_addr()).await.unwrap();
+            let addr = config
+                .get_addr()
+                .expect("TokioSyncClient only supports TCP hosts; use Config::connect for Unix sockets/named pipes");
+
+            let tcp = TcpStream::connect(addr).await.unwrap();
             tcp.set_nodelay(true).unwrap();
             Client::connect(config, tcp.compat_write()).await.unwrap()
         });
 
         Self {
             runtime,
-            client 
+            client
         }
     }
 
@@ -40,6 +45,15 @@ impl TokioSyncClient {
             Ok(SyncQueryStream::new(&self.runtime, query_stream))
         })
     }
+
+    pub fn execute<'a, 'b>(&'a mut self, sql: &'b str, params: &[&'b dyn ToSql]) -> crate::Result<ExecuteResult>
+    where
+    'a: 'b,
+    {
+        self.runtime.block_on(async {
+            self.client.execute(sql, params).await
+        })
+    }
 }
 
 pub struct SyncQueryStream<'a> {
@@ -60,4 +74,28 @@ impl<'a> SyncQueryStream<'a> {
             self.query_stream.into_row().await
         })
     }
-}
\ No newline at end of file
+
+    /// Blocking equivalent of `QueryStream::into_results`, collecting every
+    /// result set produced by a batch into its own `Vec<Row>`.
+    pub fn into_results(self) -> crate::Result<Vec<Vec<Row>>> {
+        self.runtime.block_on(async {
+            self.query_stream.into_results().await
+        })
+    }
+
+    /// Blocking equivalent of `QueryStream::into_first_result`, returning
+    /// only the rows of the first result set.
+    pub fn into_first_result(self) -> crate::Result<Vec<Row>> {
+        self.runtime.block_on(async {
+            self.query_stream.into_first_result().await
+        })
+    }
+
+    /// Blocking row-at-a-time iteration over the current result set,
+    /// mirroring polling the async `QueryStream` directly.
+    pub fn next(&mut self) -> crate::Result<Option<Row>> {
+        self.runtime.block_on(async {
+            self.query_stream.next().await.transpose()
+        })
+    }
+}
@@ -0,0 +1,187 @@
+//! Local transport support: connecting to a SQL Server instance over a Unix
+//! domain socket or a Windows named pipe, in addition to plain TCP.
+//!
+//! Only wired up for the `tokio` runtime, since `tokio::net` is what
+//! provides `UnixStream` and `named_pipe::NamedPipeClient`.
+use futures_util::io::{AsyncRead, AsyncWrite};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
+
+/// The address form a [`Config`] resolves to, parsed from its configured
+/// host and port (not from [`Config::get_addr`], which returns `None` for
+/// the non-TCP forms): a regular TCP host/port, a Unix domain socket path,
+/// or (on Windows) a named pipe path.
+///
+/// [`Config`]: struct.Config.html
+/// [`Config::get_addr`]: struct.Config.html#method.get_addr
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Address {
+    Tcp(String, u16),
+    Unix(String),
+    NamedPipe(String),
+}
+
+impl Address {
+    /// Parses `host` (and `port`, used only for the `Tcp` case) into an
+    /// [`Address`]. A `np:` prefix selects a named pipe, a `unix:` prefix
+    /// or a path starting with `/` selects a Unix domain socket, anything
+    /// else is a regular TCP host.
+    pub(crate) fn parse(host: &str, port: u16) -> Self {
+        if let Some(pipe) = host.strip_prefix("np:") {
+            Address::NamedPipe(pipe.to_string())
+        } else if let Some(path) = host.strip_prefix("unix:") {
+            Address::Unix(path.to_string())
+        } else if host.starts_with('/') {
+            Address::Unix(host.to_string())
+        } else {
+            Address::Tcp(host.to_string(), port)
+        }
+    }
+}
+
+/// A connected transport: TCP, a Unix domain socket, or a Windows named
+/// pipe, unified behind one `AsyncRead + AsyncWrite` type so the rest of
+/// the client doesn't need to know which one it's talking to.
+pub(crate) enum Transport {
+    Tcp(Compat<tokio::net::TcpStream>),
+    #[cfg(unix)]
+    Unix(Compat<tokio::net::UnixStream>),
+    #[cfg(windows)]
+    NamedPipe(Compat<tokio::net::windows::named_pipe::NamedPipeClient>),
+}
+
+/// Opens `address`, picking the right socket type and disabling Nagle on
+/// TCP connections.
+pub(crate) async fn connect(address: &Address) -> crate::Result<Transport> {
+    match address {
+        Address::Tcp(host, port) => {
+            let tcp = tokio::net::TcpStream::connect((host.as_str(), *port)).await?;
+            tcp.set_nodelay(true)?;
+
+            Ok(Transport::Tcp(tcp.compat_write()))
+        }
+        #[cfg(unix)]
+        Address::Unix(path) => {
+            let socket = tokio::net::UnixStream::connect(path).await?;
+            Ok(Transport::Unix(socket.compat_write()))
+        }
+        #[cfg(not(unix))]
+        Address::Unix(_) => Err(crate::error::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Unix domain sockets are only supported on Unix platforms",
+        ))),
+        #[cfg(windows)]
+        Address::NamedPipe(pipe) => {
+            let client = tokio::net::windows::named_pipe::ClientOptions::new()
+                .open(pipe)
+                .map_err(crate::error::Error::Io)?;
+
+            Ok(Transport::NamedPipe(client.compat_write()))
+        }
+        #[cfg(not(windows))]
+        Address::NamedPipe(_) => Err(crate::error::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "named pipes are only supported on Windows",
+        ))),
+    }
+}
+
+impl AsyncRead for Transport {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            Transport::Tcp(s) => Pin::new(s).poll_read(cx, buf),
+            #[cfg(unix)]
+            Transport::Unix(s) => Pin::new(s).poll_read(cx, buf),
+            #[cfg(windows)]
+            Transport::NamedPipe(s) => Pin::new(s).poll_read(cx, buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Address;
+
+    #[test]
+    fn parses_plain_host_as_tcp() {
+        assert_eq!(
+            Address::parse("localhost", 1433),
+            Address::Tcp("localhost".to_string(), 1433)
+        );
+    }
+
+    #[test]
+    fn parses_ip_as_tcp() {
+        assert_eq!(
+            Address::parse("127.0.0.1", 1433),
+            Address::Tcp("127.0.0.1".to_string(), 1433)
+        );
+    }
+
+    #[test]
+    fn parses_named_pipe() {
+        assert_eq!(
+            Address::parse(r"np:\\.\pipe\sql\query", 1433),
+            Address::NamedPipe(r"\\.\pipe\sql\query".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_unix_prefixed_socket() {
+        assert_eq!(
+            Address::parse("unix:/tmp/tiberius.sock", 1433),
+            Address::Unix("/tmp/tiberius.sock".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_bare_slash_as_unix_socket() {
+        assert_eq!(
+            Address::parse("/tmp/tiberius.sock", 1433),
+            Address::Unix("/tmp/tiberius.sock".to_string())
+        );
+    }
+}
+
+impl AsyncWrite for Transport {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        match self.get_mut() {
+            Transport::Tcp(s) => Pin::new(s).poll_write(cx, buf),
+            #[cfg(unix)]
+            Transport::Unix(s) => Pin::new(s).poll_write(cx, buf),
+            #[cfg(windows)]
+            Transport::NamedPipe(s) => Pin::new(s).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Transport::Tcp(s) => Pin::new(s).poll_flush(cx),
+            #[cfg(unix)]
+            Transport::Unix(s) => Pin::new(s).poll_flush(cx),
+            #[cfg(windows)]
+            Transport::NamedPipe(s) => Pin::new(s).poll_flush(cx),
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        match self.get_mut() {
+            Transport::Tcp(s) => Pin::new(s).poll_close(cx),
+            #[cfg(unix)]
+            Transport::Unix(s) => Pin::new(s).poll_close(cx),
+            #[cfg(windows)]
+            Transport::NamedPipe(s) => Pin::new(s).poll_close(cx),
+        }
+    }
+}